@@ -1,10 +1,22 @@
-use std::{cmp::Ordering, fmt::{self, Display}, str::FromStr};
+use std::{
+    cmp::Ordering,
+    error::Error,
+    fmt::{self, Display},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
+    str::FromStr,
+};
+
+use num_traits::{Num, One, Zero};
 
 /// represents a numerical value in a SPICE file.
 ///
 /// primarily intended to be parsed from a string using [`Number::from_str()`].
-/// Internally, the input is parsed to an [`f64`], so input rules of [`f64::from_str()`] apply
-/// (except `inf`, `-inf`, and `NaN`).
+/// Internally, the input is parsed to an exact `mantissa * 10^scale` decimal (see
+/// [`value_exact()`](Number::value_exact)), from which the [`f64`] convenience value is
+/// derived, so input rules of [`f64::from_str()`] apply (except `inf`, `-inf`, and `NaN`).
+/// [`PartialEq`] and [`PartialOrd`] compare the exact form, not the [`f64`], so two values that
+/// round to the same `f64` but differ in the input text (e.g. `1.2k` vs `1200.000000000000001`) compare
+/// correctly.
 ///
 /// Values can also be appended with SI prefixes to denote magnitude, instead of using `n.nnEnn` notation.
 /// For example, `1.23k` would be parsed to `1230.0`. The following case-insensitive values are allowed:
@@ -21,59 +33,524 @@ use std::{cmp::Ordering, fmt::{self, Display}, str::FromStr};
 /// | P      | Pico   | `E-12`              |
 /// | F      | Femto  | `E-15`              |
 ///
+/// Alternatively, the whole value can be given as an alternate-radix integer literal: a leading
+/// `0x`/`0X` for hex, `0b`/`0B` for binary, or `0o`/`0O`/a bare leading `0` for octal, followed by
+/// digits in that base. An SI multiplier may still follow (e.g. `0x10k`), but a radix literal
+/// cannot also have a decimal point or `e`/`E` exponent.
+///
 /// [`f64::from_str()`]: https://doc.rust-lang.org/1.56.0/std/primitive.f64.html#method.from_str
 // TODO: Fix `f64::from_str()` link (see rust-lang/rust#90703)
 #[derive(Debug)]
 pub struct Number {
     pub value: f64,
     pub raw: String,
+    mantissa: i128,
+    scale: i32,
+    unit: Option<Unit>,
+}
+
+impl Number {
+    /// builds a `Number` from an exact `mantissa * 10^scale` decimal value, deriving the
+    /// (possibly lossy) [`f64`] convenience value from it.
+    fn from_parts(mantissa: i128, scale: i32, raw: String) -> Self {
+        let value = (mantissa as f64) * 10f64.powi(scale);
+        Self { value, raw, mantissa, scale, unit: None }
+    }
+
+    /// sets the physical unit captured while parsing this `Number`.
+    fn with_unit(mut self, unit: Option<Unit>) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    /// the physical unit captured from the input, if any (see [`Unit`]).
+    pub fn unit(&self) -> Option<Unit> {
+        self.unit
+    }
+
+    /// like [`eq()`](PartialEq::eq), but additionally requires `self` and `other` to have the
+    /// same [`unit()`](Number::unit), so e.g. `1k` and `1kOhm` compare unequal despite having
+    /// the same magnitude.
+    pub fn eq_with_unit(&self, other: &Self) -> bool {
+        self.unit == other.unit && self == other
+    }
+
+    /// like [`partial_cmp()`](PartialOrd::partial_cmp), but returns `None` if `self` and `other`
+    /// have different [`unit()`](Number::unit)s instead of comparing across units.
+    pub fn partial_cmp_with_unit(&self, other: &Self) -> Option<Ordering> {
+        if self.unit != other.unit {
+            return None;
+        }
+        self.partial_cmp(other)
+    }
+
+    /// returns the exact value this `Number` represents, as a `(mantissa, scale)` pair where
+    /// the represented value is `mantissa * 10^scale`.
+    ///
+    /// unlike [`value`](Number::value), this is not subject to [`f64`] rounding, since
+    /// [`from_str()`](Number::from_str) parses the input directly into this scaled-integer form.
+    pub fn value_exact(&self) -> (i128, i32) {
+        (self.mantissa, self.scale)
+    }
+
+    /// cross-multiplies the exact `mantissa * 10^scale` values of `self` and `other` onto a
+    /// common scale so they can be compared without going through [`f64`].
+    ///
+    /// returns `None` if aligning the scales would overflow [`i128`], in which case callers
+    /// should fall back to comparing [`value`](Number::value).
+    fn cmp_exact(&self, other: &Self) -> Option<Ordering> {
+        match self.scale.cmp(&other.scale) {
+            Ordering::Equal => Some(self.mantissa.cmp(&other.mantissa)),
+            Ordering::Less => {
+                let shift = (other.scale - self.scale) as u32;
+                let scaled = 10i128.checked_pow(shift)?.checked_mul(other.mantissa)?;
+                Some(self.mantissa.cmp(&scaled))
+            }
+            Ordering::Greater => {
+                let shift = (self.scale - other.scale) as u32;
+                let scaled = 10i128.checked_pow(shift)?.checked_mul(self.mantissa)?;
+                Some(scaled.cmp(&other.mantissa))
+            }
+        }
+    }
+
+    /// rescales `self` and `other`'s mantissas onto their common (lesser) scale, so they can be
+    /// added or subtracted directly. returns `None` if doing so would overflow [`i128`].
+    fn aligned_mantissas(&self, other: &Self) -> Option<(i128, i128, i32)> {
+        let scale = self.scale.min(other.scale);
+        let self_mantissa = self.mantissa.checked_mul(10i128.checked_pow((self.scale - scale) as u32)?)?;
+        let other_mantissa = other.mantissa.checked_mul(10i128.checked_pow((other.scale - scale) as u32)?)?;
+        Some((self_mantissa, other_mantissa, scale))
+    }
+
+    /// builds a `Number` from an exact `mantissa * 10^scale` arithmetic result, synthesizing a
+    /// `raw` string from the resulting value since there's no original input text to preserve.
+    fn synthesize(mantissa: i128, scale: i32) -> Self {
+        let value = (mantissa as f64) * 10f64.powi(scale);
+        Self::from_parts(mantissa, scale, format!("{value}"))
+    }
+
+    /// builds a `Number` from an [`f64`] arithmetic result (e.g. division) that can't generally
+    /// be represented as an exact decimal, by parsing its formatted value back into one.
+    ///
+    /// panics if `value` is not finite (e.g. a division by zero), matching the other arithmetic
+    /// operators' behavior on overflow, rather than building a `Number` whose `raw` disagrees
+    /// with its `value`/`value_exact()`.
+    fn from_value(value: f64) -> Self {
+        assert!(value.is_finite(), "Number division produced a non-finite result");
+        let raw = format!("{value}");
+        let (mantissa, scale) = parse_decimal(&raw).expect("Number division result overflowed i128");
+        Self::from_parts(mantissa, scale, raw)
+    }
+
+    /// formats [`value`](Number::value) in SPICE engineering notation, normalizing its
+    /// magnitude into the nearest SI prefix from the table above so the mantissa lands in
+    /// `[1, 1000)`, with `sig_digits` significant digits.
+    ///
+    /// rounding to `sig_digits` can carry the mantissa up to `1000`, in which case this shifts up
+    /// to the next prefix tier to restore the `[1, 1000)` invariant (e.g. `999.995` at 3 sig figs
+    /// is `1.00k`, not `1000`) — except at `T`, the largest prefix, where there's no further tier
+    /// to shift into and the mantissa is left as-is (e.g. `1e15` formats as `"1000T"`). Magnitudes
+    /// below `1f` likewise keep a mantissa below `1` (e.g. `1e-18` formats as `"0.00f"`).
+    ///
+    /// this is the inverse of the SI-prefix parsing [`from_str()`](Number::from_str) does.
+    pub fn to_si_string(&self, sig_digits: usize) -> String {
+        const PREFIXES: [(i32, &str); 10] = [
+            (12, "T"), (9, "G"), (6, "Meg"), (3, "k"), (0, ""),
+            (-3, "m"), (-6, "u"), (-9, "n"), (-12, "p"), (-15, "f"),
+        ];
+
+        if self.value == 0.0 {
+            return String::from("0");
+        }
+
+        let magnitude = self.value.abs().log10().floor() as i32;
+        let exp = magnitude.div_euclid(3) * 3;
+        let mut prefix_idx = PREFIXES.iter().position(|(e, _)| *e <= exp).unwrap_or(PREFIXES.len() - 1);
+
+        loop {
+            let exp = PREFIXES[prefix_idx].0;
+            let mantissa = self.value / 10f64.powi(exp);
+            let int_digits = |m: f64| if m.abs() < 10.0 { 1 } else if m.abs() < 100.0 { 2 } else { 3 };
+            let decimals = sig_digits.saturating_sub(int_digits(mantissa));
+            let formatted = format!("{mantissa:.decimals$}");
+            let rounded: f64 = formatted.parse().unwrap_or(mantissa);
+
+            // rounding can carry a digit into the next order of magnitude within this tier (e.g.
+            // `99.96` -> `"100.0"`), which claims one more significant digit than requested -
+            // reformat with the digit budget the carried value actually needs.
+            let formatted = if int_digits(rounded) > int_digits(mantissa) {
+                let decimals = sig_digits.saturating_sub(int_digits(rounded));
+                format!("{mantissa:.decimals$}")
+            } else {
+                formatted
+            };
+
+            // only shift to the next prefix tier once the rounded mantissa actually reaches 1000,
+            // not merely whenever its digit count changes
+            if rounded.abs() >= 1000.0 && prefix_idx > 0 {
+                prefix_idx -= 1;
+                continue;
+            }
+
+            return format!("{formatted}{}", PREFIXES[prefix_idx].1);
+        }
+    }
+}
+
+impl Zero for Number {
+    fn zero() -> Self {
+        Number::from_parts(0, 0, String::from("0"))
+    }
+
+    fn is_zero(&self) -> bool {
+        self.mantissa == 0
+    }
+}
+
+impl One for Number {
+    fn one() -> Self {
+        Number::from_parts(1, 0, String::from("1"))
+    }
+}
+
+impl Num for Number {
+    type FromStrRadixErr = ParseNumberError;
+
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        if radix != 10 {
+            return Err(ParseNumberError{kind: NumberErrorKind::Invalid, position: 0});
+        }
+        Self::from_str(str)
+    }
+}
+
+impl Add for Number {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let (self_mantissa, rhs_mantissa, scale) = self.aligned_mantissas(&rhs)
+            .expect("Number addition overflowed i128");
+        Self::synthesize(self_mantissa + rhs_mantissa, scale)
+    }
+}
+
+impl Sub for Number {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let (self_mantissa, rhs_mantissa, scale) = self.aligned_mantissas(&rhs)
+            .expect("Number subtraction overflowed i128");
+        Self::synthesize(self_mantissa - rhs_mantissa, scale)
+    }
+}
+
+impl Mul for Number {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)] // adding the scales is correct: 10^a * 10^b = 10^(a+b)
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mantissa = self.mantissa.checked_mul(rhs.mantissa)
+            .expect("Number multiplication overflowed i128");
+        Self::synthesize(mantissa, self.scale + rhs.scale)
+    }
+}
+
+impl Div for Number {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Self::from_value(self.value / rhs.value)
+    }
+}
+
+impl Rem for Number {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        let (self_mantissa, rhs_mantissa, scale) = self.aligned_mantissas(&rhs)
+            .expect("Number remainder overflowed i128");
+        Self::synthesize(self_mantissa % rhs_mantissa, scale)
+    }
+}
+
+impl Neg for Number {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::synthesize(-self.mantissa, self.scale)
+    }
+}
+
+impl AddAssign for Number {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = std::mem::take(self) + rhs;
+    }
+}
+
+impl SubAssign for Number {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = std::mem::take(self) - rhs;
+    }
+}
+
+impl MulAssign for Number {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = std::mem::take(self) * rhs;
+    }
+}
+
+impl DivAssign for Number {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = std::mem::take(self) / rhs;
+    }
+}
+
+impl RemAssign for Number {
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = std::mem::take(self) % rhs;
+    }
 }
 
 impl PartialEq for Number {
     fn eq(&self, other: &Self) -> bool {
-        self.value == other.value
+        self.cmp_exact(other)
+            .map(|ord| ord == Ordering::Equal)
+            .unwrap_or_else(|| self.value == other.value)
     }
 }
 
 impl PartialOrd for Number {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.value.partial_cmp(&other.value)
+        self.cmp_exact(other)
+            .or_else(|| self.value.partial_cmp(&other.value))
     }
 }
 
 impl Default for Number {
     fn default() -> Self {
-        Number {
-            value: 0.0,
-            raw: String::from("0"),
-        }
+        Number::from_parts(0, 0, String::from("0"))
     }
 }
 
 impl Display for Number {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.raw)
+        if f.alternate() {
+            write!(f, "{}", self.to_si_string(3))
+        } else {
+            write!(f, "{}", self.raw)
+        }
     }
 }
 
-impl FromStr for Number {
-    type Err = ParseNumberError;
+/// a physical unit that may trail a parsed SPICE value, e.g. the `F` in `1.23pF`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unit {
+    Farad,
+    Henry,
+    Ohm,
+    Volt,
+    Amp,
+    Second,
+    Hertz,
+}
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut raw = s.chars();
+impl Unit {
+    /// looks up a physical unit by its case-insensitive SPICE token (`F`/`Farad`, `H`/`Henry`,
+    /// `Ohm`, `V`, `A`, `s`, `Hz`, and common pluralizations), or `None` if `token` isn't one.
+    fn lookup(token: &str) -> Option<Self> {
+        match token.to_ascii_lowercase().as_str() {
+            "f" | "farad" | "farads" => Some(Unit::Farad),
+            "h" | "henry" | "henries" => Some(Unit::Henry),
+            "ohm" | "ohms" => Some(Unit::Ohm),
+            "v" | "volt" | "volts" => Some(Unit::Volt),
+            "a" | "amp" | "amps" | "ampere" | "amperes" => Some(Unit::Amp),
+            "s" | "sec" | "secs" | "second" | "seconds" => Some(Unit::Second),
+            "hz" | "hertz" => Some(Unit::Hertz),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", match self {
+            Unit::Farad => "F",
+            Unit::Henry => "H",
+            Unit::Ohm => "Ohm",
+            Unit::Volt => "V",
+            Unit::Amp => "A",
+            Unit::Second => "s",
+            Unit::Hertz => "Hz",
+        })
+    }
+}
+
+/// parses the digit run collected by [`Number::from_str()`] (sign, integer/fractional digits,
+/// and an optional `e`/`E` exponent — no SI multiplier) into an exact `mantissa * 10^scale` pair.
+///
+/// returns `None` if the digit run's magnitude doesn't fit in an [`i128`], rather than silently
+/// truncating it to `0`.
+fn parse_decimal(value_str: &str) -> Option<(i128, i32)> {
+    let (digits_part, exponent) = match value_str.find(['e', 'E']) {
+        Some(pos) => (&value_str[..pos], value_str[pos + 1..].parse::<i32>().ok()?),
+        None => (value_str, 0),
+    };
+
+    let (sign, digits_part) = match digits_part.strip_prefix('-') {
+        Some(rest) => (-1i128, rest),
+        None => (1i128, digits_part.strip_prefix('+').unwrap_or(digits_part)),
+    };
+
+    let (int_part, frac_part) = match digits_part.find('.') {
+        Some(pos) => (&digits_part[..pos], &digits_part[pos + 1..]),
+        None => (digits_part, ""),
+    };
+
+    let magnitude: i128 = format!("{int_part}{frac_part}").parse().ok()?;
+    let mantissa = sign * magnitude;
+    let scale = exponent - frac_part.len() as i32;
+
+    Some((mantissa, scale))
+}
+
+/// parses the SI multiplier and/or physical unit (if any) starting at the beginning of `tail`,
+/// which is everything left in the input after the digit run. `base_idx` is `tail`'s byte offset
+/// in the original input, used to place error positions.
+///
+/// a unit name (`Ohm`, `V`, `Hz`, ...) takes priority over the SI multiplier table, *except*
+/// that a lone `F`/`f` is always the femto multiplier rather than the `Farad` abbreviation, for
+/// backwards compatibility with plain values like `5F`; spelling out `Farad`/`Farads` is how
+/// that ambiguity is resolved instead. When `strict`, a trailing tail that doesn't parse as
+/// either is [`NumberErrorKind::InvalidUnit`] instead of being silently discarded.
+fn parse_unit_suffix(tail: &str, base_idx: usize, strict: bool) -> Result<(i32, Option<Unit>), ParseNumberError> {
+    if tail.is_empty() {
+        return Ok((0, None));
+    }
+
+    let first = tail.chars().next().unwrap();
+    if !(tail.len() == 1 && first.eq_ignore_ascii_case(&'f')) {
+        if let Some(unit) = Unit::lookup(tail) {
+            return Ok((0, Some(unit)));
+        }
+    }
+
+    if !first.is_ascii_alphabetic() {
+        return Err(ParseNumberError{kind: NumberErrorKind::Invalid, position: base_idx});
+    }
+
+    let (scale, consumed) = match first.to_ascii_uppercase() {
+        'T' => (12, 1), // Tera
+        'G' => (9, 1), // Giga
+        'X' => (6, 1), // Mega
+        'K' => (3, 1), // Kilo
+        'M' => { // Milli (m) or Mega (Meg)
+            let lookahead: String = tail.chars().skip(1).take(2).collect();
+            if lookahead.eq_ignore_ascii_case("EG") { (6, 3) } else { (-3, 1) }
+        }
+        'U' => (-6, 1), // Micro
+        'N' => (-9, 1), // Nano
+        'P' => (-12, 1), // Pico
+        'F' => (-15, 1), // Femto
+        _ => return Err(ParseNumberError{kind: NumberErrorKind::InvalidMult, position: base_idx}),
+    };
+
+    let remainder = &tail[consumed..];
+    if remainder.is_empty() {
+        return Ok((scale, None));
+    }
+    match Unit::lookup(remainder) {
+        Some(unit) => Ok((scale, Some(unit))),
+        None if strict => Err(ParseNumberError{kind: NumberErrorKind::InvalidUnit, position: base_idx + consumed}),
+        None => Ok((scale, None)),
+    }
+}
+
+/// attempts to parse a WGSL-style alternate-radix integer literal (`0x`/`0X` hex, `0b`/`0B`
+/// binary, `0o`/`0O`/bare-leading-`0` octal) from the start of `s`.
+///
+/// returns `None` if `s` doesn't start with a radix prefix, so [`Number::from_str()`] can fall
+/// back to the decimal/exponent grammar. Otherwise returns the parsed mantissa together with the
+/// byte length of `s` consumed by the literal (sign, prefix, and digits — not the SI suffix), or
+/// the error if the literal was malformed.
+fn parse_radix_literal(s: &str) -> Option<Result<(i128, usize), ParseNumberError>> {
+    let (sign, rest) = match s.strip_prefix('-') {
+        Some(rest) => (-1i128, rest),
+        None => (1i128, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let sign_len = s.len() - rest.len();
+
+    let (radix, digits_start): (u32, usize) = if rest.starts_with("0x") || rest.starts_with("0X") {
+        (16, 2)
+    } else if rest.starts_with("0b") || rest.starts_with("0B") {
+        (2, 2)
+    } else if rest.starts_with("0o") || rest.starts_with("0O") {
+        (8, 2)
+    } else if rest.len() > 1 && rest.starts_with('0') && rest.as_bytes()[1].is_ascii_digit() {
+        // only commit to octal if the whole leading digit run is valid octal AND isn't actually
+        // the integer part of an ordinary decimal float/exponent (e.g. `08`, `09.5`, `017.5`,
+        // `017e2`); otherwise fall back to `None` and let the decimal grammar below parse it
+        let digit_run_len = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+        let followed_by_decimal_syntax = matches!(rest[digit_run_len..].chars().next(), Some('.' | 'e' | 'E'));
+        if !followed_by_decimal_syntax && rest.as_bytes()[..digit_run_len].iter().all(|b| (b'0'..=b'7').contains(b)) {
+            (8, 0)
+        } else {
+            return None;
+        }
+    } else {
+        return None;
+    };
+
+    let digit_section = &rest[digits_start..];
+    let digit_len = digit_section.find(|c: char| c.to_digit(radix).is_none()).unwrap_or(digit_section.len());
+    let digits_end = digits_start + digit_len;
+
+    if digit_len == 0 {
+        return Some(Err(ParseNumberError{kind: NumberErrorKind::Invalid, position: sign_len + digits_start}));
+    }
+
+    // a radix literal can't also carry a decimal point or an exponent
+    if let Some(c) = rest[digits_end..].chars().next() {
+        if c == '.' || (radix != 16 && (c == 'e' || c == 'E')) {
+            return Some(Err(ParseNumberError{kind: NumberErrorKind::Invalid, position: sign_len + digits_end}));
+        }
+    }
+
+    match i128::from_str_radix(&rest[digits_start..digits_end], radix) {
+        Ok(magnitude) => Some(Ok((sign * magnitude, sign_len + digits_end))),
+        Err(_) => Some(Err(ParseNumberError{kind: NumberErrorKind::Overflow, position: sign_len + digits_start})),
+    }
+}
+
+impl Number {
+    /// the shared implementation behind [`from_str()`](Number::from_str) and
+    /// [`from_str_strict()`](Number::from_str_strict): parses `s` into a `Number`, optionally
+    /// requiring (`strict`) that a trailing unit tail be a recognized [`Unit`] rather than
+    /// silently discarding it.
+    fn parse(s: &str, strict: bool) -> Result<Self, ParseNumberError> {
+        if let Some(result) = parse_radix_literal(s) {
+            let (mantissa, consumed) = result?;
+            let (mult_scale, unit) = parse_unit_suffix(&s[consumed..], consumed, strict)?;
+            return Ok(Self::from_parts(mantissa, mult_scale, s.into()).with_unit(unit));
+        }
+
+        let mut raw = s.char_indices();
         let mut state = NumParseState::Start;
         let mut next_state: NumParseState;
+        let mut idx: usize;
         let mut c: char;
         let mut value_str = String::new();
-        let mut mult = 1.0;
+        let mut mult_scale: i32 = 0;
+        let mut unit: Option<Unit> = None;
 
         'parse: loop {
-            if let Some(ch) = raw.next() {
+            if let Some((i, ch)) = raw.next() {
+                idx = i;
                 c = ch;
-            } else if value_str.len() > 0 {
+            } else if !value_str.is_empty() {
                 break 'parse;
             } else {
-                return Err(ParseNumberError{kind: NumberErrorKind::Empty});
+                return Err(ParseNumberError{kind: NumberErrorKind::Empty, position: 0});
             }
 
             match state {
@@ -83,43 +560,31 @@ impl FromStr for Number {
                         if state == NumParseState::Start { next_state = NumParseState::Int; }
                             else { next_state = NumParseState::Exp; }
                     }
-                    _ => return Err(ParseNumberError{kind: NumberErrorKind::Invalid}),
+                    _ => return Err(ParseNumberError{kind: NumberErrorKind::Invalid, position: idx}),
                 },
                 NumParseState::Int | NumParseState::Float => match c {
                     '0'..='9' => {
                         value_str.push(c);
-                        next_state = NumParseState::Int;
+                        // a digit doesn't leave `Float` back to `Int`, or a second `.` would be
+                        // wrongly accepted (e.g. the middle `2` in `1.2.3`)
+                        next_state = state;
                     }
                     '.' => match state {
                         NumParseState::Int => {
                             value_str.push(c);
                             next_state = NumParseState::Float;
                         }
-                        NumParseState::Float | _ => return Err(ParseNumberError{kind: NumberErrorKind::Invalid}),
+                        _ => return Err(ParseNumberError{kind: NumberErrorKind::Invalid, position: idx}),
                     }
                     'e' | 'E' => {
                         value_str.push(c);
                         next_state = NumParseState::ExpStart;
                     }
-                    _ if c.is_ascii_alphabetic() => { // unit multiplier
-                        match c.to_ascii_uppercase() {
-                            'T' => mult = 1e12, // Tera
-                            'G' => mult = 1e9, // Giga
-                            'X' => mult = 1e6, // Mega
-                            'K' => mult = 1e3, // Kilo
-                            'M' => { // Milli (m) or Mega (Meg)
-                                if raw.take(2).collect::<String>().to_ascii_uppercase() == "EG" { mult = 1e6; }
-                                    else { mult = 1e-3; }
-                            }
-                            'U' => mult = 1e-6, // Micro
-                            'N' => mult = 1e-9, // Nano
-                            'P' => mult = 1e-12, // Pico
-                            'F' => mult = 1e-15, // Femto
-                            _ => return Err(ParseNumberError{kind: NumberErrorKind::InvalidMult}),
-                        }
+                    _ if c.is_ascii_alphabetic() => { // SI multiplier and/or unit
+                        (mult_scale, unit) = parse_unit_suffix(&s[idx..], idx, strict)?;
                         break 'parse;
                     }
-                    _ => return Err(ParseNumberError{kind: NumberErrorKind::Invalid}),
+                    _ => return Err(ParseNumberError{kind: NumberErrorKind::Invalid, position: idx}),
                 },
                 NumParseState::Exp => match c {
                     '0'..='9' => {
@@ -133,16 +598,37 @@ impl FromStr for Number {
             state = next_state;
         }
 
-        let value = match value_str.parse::<f64>() {
-            Ok(v) => v * mult,
-            Err(_) => return Err(ParseNumberError{kind: NumberErrorKind::Invalid})
-        };
+        if value_str.parse::<f64>().is_err() {
+            // ran out of input partway through a token (e.g. a bare sign or a
+            // dangling exponent marker); report the failure at the end of the string
+            return Err(ParseNumberError{kind: NumberErrorKind::Invalid, position: s.len()});
+        }
+
+        let (mantissa, digit_scale) = parse_decimal(&value_str)
+            .ok_or(ParseNumberError{kind: NumberErrorKind::Overflow, position: 0})?;
+        Ok(Self::from_parts(mantissa, digit_scale + mult_scale, s.into()).with_unit(unit))
+    }
 
-        Ok(Self{ value, raw: s.into() })
+    /// like [`from_str()`](Number::from_str), but an unrecognized unit tail following the SI
+    /// multiplier (e.g. the `Frobs` in `1.2kFrobs`) is a [`NumberErrorKind::InvalidUnit`] error
+    /// instead of being silently discarded.
+    ///
+    /// anything following an `e`/`E` exponent (e.g. the `Frobs` in `1e3Frobs`) is not checked: it
+    /// is discarded in both modes, along with any SI multiplier or unit it contains.
+    pub fn from_str_strict(s: &str) -> Result<Self, ParseNumberError> {
+        Self::parse(s, true)
     }
 }
 
-#[derive(PartialEq)]
+impl FromStr for Number {
+    type Err = ParseNumberError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, false)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
 enum NumParseState {
     Start,
     Int,
@@ -151,29 +637,59 @@ enum NumParseState {
     Exp,
 }
 
+/// the error returned by [`Number::from_str()`] when the input cannot be parsed.
+///
+/// exposes the [`kind`](ParseNumberError::kind) of failure along with the
+/// [`position`](ParseNumberError::position) (a byte offset into the input) where parsing bailed out,
+/// so callers can build diagnostics like `invalid multiplier at column 6`.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ParseNumberError {
     kind: NumberErrorKind,
+    position: usize,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-enum NumberErrorKind {
-    Empty,
-    Invalid,
-    InvalidMult,
+impl ParseNumberError {
+    /// the kind of parse failure that occurred.
+    pub fn kind(&self) -> NumberErrorKind {
+        self.kind
+    }
+
+    /// the byte offset into the input at which parsing failed.
+    pub fn position(&self) -> usize {
+        self.position
+    }
 }
 
-impl ParseNumberError {
-    #[doc(hidden)]
-    pub fn __description(&self) -> &str {
+impl Display for ParseNumberError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self.kind {
-            NumberErrorKind::Empty => "cannot parse number from empty string",
-            NumberErrorKind::Invalid => "invalid number",
-            NumberErrorKind::InvalidMult => "invalid multiplier",
+            NumberErrorKind::Empty => write!(f, "cannot parse number from empty string"),
+            NumberErrorKind::Invalid => write!(f, "invalid number at column {}", self.position + 1),
+            NumberErrorKind::InvalidMult => write!(f, "invalid multiplier at column {}", self.position + 1),
+            NumberErrorKind::InvalidUnit => write!(f, "invalid unit at column {}", self.position + 1),
+            NumberErrorKind::Overflow => write!(f, "number at column {} is too large to represent", self.position + 1),
         }
     }
 }
 
+impl Error for ParseNumberError {}
+
+/// the kind of failure recorded by a [`ParseNumberError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumberErrorKind {
+    /// the input was an empty string.
+    Empty,
+    /// the input contained a character that is not valid in a number.
+    Invalid,
+    /// the input ended in an SI multiplier suffix that was not recognized.
+    InvalidMult,
+    /// ([`Number::from_str_strict()`] only) the input ended in a unit tail that was not a
+    /// recognized [`Unit`].
+    InvalidUnit,
+    /// the digit run's magnitude doesn't fit in the exact `mantissa * 10^scale` representation.
+    Overflow,
+}
+
 pub type Node = String;
 
 
@@ -183,14 +699,14 @@ mod tests {
 
     #[test]
     fn default() {
-        assert_eq!(Number::default(), Number{value: 0.0, raw: String::from("0")})
+        assert_eq!(Number::default(), Number::from_parts(0, 0, String::from("0")))
     }
 
     #[test]
     fn int() {
         assert_eq!(
             Number::from_str("7343"),
-            Ok( Number{ value: 7343.0, raw: String::from("7343") } )
+            Ok( Number::from_parts(7343, 0, String::from("7343")) )
         )
     }
 
@@ -198,7 +714,7 @@ mod tests {
     fn plus_int() {
         assert_eq!(
             Number::from_str("+123"),
-            Ok( Number{ value: 123.0, raw: String::from("+123") } )
+            Ok( Number::from_parts(123, 0, String::from("+123")) )
         )
     }
 
@@ -206,7 +722,7 @@ mod tests {
     fn minus_int() {
         assert_eq!(
             Number::from_str("-453"),
-            Ok( Number{ value: -453.0, raw: String::from("-453") } )
+            Ok( Number::from_parts(-453, 0, String::from("-453")) )
         )
     }
 
@@ -214,7 +730,7 @@ mod tests {
     fn float() {
         assert_eq!(
             Number::from_str("1.23"),
-            Ok( Number{ value: 1.23, raw: String::from("1.23") } )
+            Ok( Number::from_parts(123, -2, String::from("1.23")) )
         )
     }
 
@@ -222,7 +738,7 @@ mod tests {
     fn plus_float() {
         assert_eq!(
             Number::from_str("+87343.54"),
-            Ok( Number{ value: 87343.54, raw: String::from("+87343.54") } )
+            Ok( Number::from_parts(8734354, -2, String::from("+87343.54")) )
         )
     }
 
@@ -230,7 +746,7 @@ mod tests {
     fn minus_float() {
         assert_eq!(
             Number::from_str("-8484.00927"),
-            Ok( Number{ value: -8484.00927, raw: String::from("-8484.00927") } )
+            Ok( Number::from_parts(-848400927, -5, String::from("-8484.00927")) )
         )
     }
 
@@ -238,7 +754,7 @@ mod tests {
     fn plus_int_exp_lower() {
         assert_eq!(
             Number::from_str("+473e3"),
-            Ok( Number{ value: 473e3, raw: String::from("+473e3") } )
+            Ok( Number::from_parts(473, 3, String::from("+473e3")) )
         )
     }
 
@@ -246,7 +762,7 @@ mod tests {
     fn minus_int_exp_upper_plus() {
         assert_eq!(
             Number::from_str("-234E+7"),
-            Ok( Number{ value: -234e7, raw: String::from("-234E+7") } )
+            Ok( Number::from_parts(-234, 7, String::from("-234E+7")) )
         )
     }
 
@@ -254,7 +770,7 @@ mod tests {
     fn int_exp_lower_plus_leading_zeros() {
         assert_eq!(
             Number::from_str("34e+0007"),
-            Ok( Number{ value: 34e7, raw: String::from("34e+0007") } )
+            Ok( Number::from_parts(34, 7, String::from("34e+0007")) )
         )
     }
 
@@ -262,7 +778,7 @@ mod tests {
     fn int_exp_upper_minus() {
         assert_eq!(
             Number::from_str("4E-2"),
-            Ok( Number{ value: 4e-2, raw: String::from("4E-2") } )
+            Ok( Number::from_parts(4, -2, String::from("4E-2")) )
         )
     }
 
@@ -270,7 +786,7 @@ mod tests {
     fn minus_int_exp_upper_minus_leading_zeros() {
         assert_eq!(
             Number::from_str("-4E-08"),
-            Ok( Number{ value: -4e-8, raw: String::from("1.23") } )
+            Ok( Number::from_parts(-4, -8, String::from("-4E-08")) )
         )
     }
 
@@ -278,7 +794,7 @@ mod tests {
     fn plus_float_exp_lower() {
         assert_eq!(
             Number::from_str("+4.73e3"),
-            Ok( Number{ value: 4.73e3, raw: String::from("+4.73e3") } )
+            Ok( Number::from_parts(473, 1, String::from("+4.73e3")) )
         )
     }
 
@@ -286,7 +802,7 @@ mod tests {
     fn minus_float_exp_upper_plus() {
         assert_eq!(
             Number::from_str("-23.4E+7"),
-            Ok( Number{ value: -23.4e7, raw: String::from("-23.4E+7") } )
+            Ok( Number::from_parts(-234, 6, String::from("-23.4E+7")) )
         )
     }
 
@@ -294,7 +810,7 @@ mod tests {
     fn float_exp_upper_plus() {
         assert_eq!(
             Number::from_str("10.34E+4"),
-            Ok( Number{ value: 10.34e4, raw: String::from("10.34E+4") } )
+            Ok( Number::from_parts(1034, 2, String::from("10.34E+4")) )
         )
     }
 
@@ -302,7 +818,7 @@ mod tests {
     fn plus_int_with_unit_lower() {
         assert_eq!(
             Number::from_str("+123t"),
-            Ok( Number{ value: 123e12, raw: String::from("+123t") } )
+            Ok( Number::from_parts(123, 12, String::from("+123t")) )
         )
     }
 
@@ -310,7 +826,7 @@ mod tests {
     fn minus_int_with_unit_upper() {
         assert_eq!(
             Number::from_str("-453X"),
-            Ok( Number{ value: -453e6, raw: String::from("-453X") } )
+            Ok( Number::from_parts(-453, 6, String::from("-453X")) )
         )
     }
 
@@ -318,7 +834,7 @@ mod tests {
     fn int_with_unit_meg() {
         assert_eq!(
             Number::from_str("7343Meg"),
-            Ok( Number{ value: 7343e6, raw: String::from("7343Meg") } )
+            Ok( Number::from_parts(7343, 6, String::from("7343Meg")) )
         )
     }
 
@@ -326,7 +842,7 @@ mod tests {
     fn float_with_unit_meg() {
         assert_eq!(
             Number::from_str("1.23Meg"),
-            Ok( Number{ value: 1.23e6, raw: String::from("1.23Meg") } )
+            Ok( Number::from_parts(123, 4, String::from("1.23Meg")) )
         )
     }
 
@@ -334,7 +850,7 @@ mod tests {
     fn plus_float_with_unit_upper() {
         assert_eq!(
             Number::from_str("+87343.54K"),
-            Ok( Number{ value: 87343.54e3, raw: String::from("+87343.54K") } )
+            Ok( Number::from_parts(8734354, 1, String::from("+87343.54K")) )
         )
     }
 
@@ -342,7 +858,7 @@ mod tests {
     fn minus_float_with_unit_lower() {
         assert_eq!(
             Number::from_str("-8484.00923m"),
-            Ok( Number{ value: -8484.00923e-3, raw: String::from("-8484.00923m") } )
+            Ok( Number::from_parts(-848400923, -8, String::from("-8484.00923m")) )
         )
     }
 
@@ -350,7 +866,7 @@ mod tests {
     fn float_with_unit_extra() {
         assert_eq!(
             Number::from_str("1.23pFarad"),
-            Ok( Number{ value: 1.23e-12, raw: String::from("1.23pFarad") } )
+            Ok( Number::from_parts(123, -14, String::from("1.23pFarad")) )
         )
     }
 
@@ -358,7 +874,7 @@ mod tests {
     fn exp_and_unit() {
         assert_eq!(
             Number::from_str("123e3F"),
-            Ok( Number{ value: 123e3, raw: String::from("123e3F") } )
+            Ok( Number::from_parts(123, 3, String::from("123e3F")) )
         )
     }
 
@@ -366,7 +882,7 @@ mod tests {
     fn invalid_empty() {
         assert_eq!(
             Number::from_str(""),
-            Err( ParseNumberError{ kind: NumberErrorKind::Empty } )
+            Err( ParseNumberError{ kind: NumberErrorKind::Empty, position: 0 } )
         )
     }
 
@@ -374,7 +890,7 @@ mod tests {
     fn invalid_multiple_points() {
         assert_eq!(
             Number::from_str("1.2.3"),
-            Err( ParseNumberError{ kind: NumberErrorKind::Invalid } )
+            Err( ParseNumberError{ kind: NumberErrorKind::Invalid, position: 3 } )
         )
     }
 
@@ -382,7 +898,7 @@ mod tests {
     fn invalid_chars1() {
         assert_eq!(
             Number::from_str("3-4"),
-            Err( ParseNumberError{ kind: NumberErrorKind::Invalid } )
+            Err( ParseNumberError{ kind: NumberErrorKind::Invalid, position: 1 } )
         )
     }
 
@@ -390,7 +906,7 @@ mod tests {
     fn invalid_chars2() {
         assert_eq!(
             Number::from_str("3+4"),
-            Err( ParseNumberError{ kind: NumberErrorKind::Invalid } )
+            Err( ParseNumberError{ kind: NumberErrorKind::Invalid, position: 1 } )
         )
     }
 
@@ -398,7 +914,7 @@ mod tests {
     fn invalid_chars3() {
         assert_eq!(
             Number::from_str("potato"),
-            Err( ParseNumberError{ kind: NumberErrorKind::Invalid } )
+            Err( ParseNumberError{ kind: NumberErrorKind::Invalid, position: 0 } )
         )
     }
 
@@ -406,7 +922,7 @@ mod tests {
     fn invalid_sign() {
         assert_eq!(
             Number::from_str("+-474.0"),
-            Err( ParseNumberError{ kind: NumberErrorKind::Invalid } )
+            Err( ParseNumberError{ kind: NumberErrorKind::Invalid, position: 1 } )
         )
     }
 
@@ -414,7 +930,397 @@ mod tests {
     fn invalid_mult() {
         assert_eq!(
             Number::from_str("474.0W"),
-            Err( ParseNumberError{ kind: NumberErrorKind::InvalidMult } )
+            Err( ParseNumberError{ kind: NumberErrorKind::InvalidMult, position: 5 } )
+        )
+    }
+
+    #[test]
+    fn invalid_overflow() {
+        assert_eq!(
+            Number::from_str("99999999999999999999999999999999999999999999999.5").unwrap_err().kind(),
+            NumberErrorKind::Overflow
         )
     }
+
+    #[test]
+    fn invalid_exponent_overflow() {
+        assert_eq!(
+            Number::from_str("1e99999999999").unwrap_err().kind(),
+            NumberErrorKind::Overflow
+        )
+    }
+
+    #[test]
+    fn error_display() {
+        assert_eq!(
+            Number::from_str("474.0W").unwrap_err().to_string(),
+            "invalid multiplier at column 6"
+        )
+    }
+
+    #[test]
+    fn value_exact() {
+        assert_eq!(Number::from_str("1.23pF").unwrap().value_exact(), (123, -14))
+    }
+
+    #[test]
+    fn exact_eq_across_scales() {
+        assert_eq!(
+            Number::from_str("1.2k").unwrap(),
+            Number::from_str("1200").unwrap()
+        )
+    }
+
+    #[test]
+    fn zero_and_one() {
+        assert_eq!(Number::zero(), Number::from_str("0").unwrap());
+        assert!(Number::zero().is_zero());
+        assert_eq!(Number::one(), Number::from_str("1").unwrap());
+    }
+
+    #[test]
+    fn from_str_radix_decimal() {
+        assert_eq!(
+            Number::from_str_radix("1.5k", 10).unwrap(),
+            Number::from_str("1.5k").unwrap()
+        )
+    }
+
+    #[test]
+    fn from_str_radix_non_decimal_is_invalid() {
+        assert_eq!(
+            Number::from_str_radix("10", 16).unwrap_err().kind(),
+            NumberErrorKind::Invalid
+        )
+    }
+
+    #[test]
+    fn add() {
+        assert_eq!(
+            Number::from_str("1.2k").unwrap() + Number::from_str("300").unwrap(),
+            Number::from_str("1500").unwrap()
+        )
+    }
+
+    #[test]
+    fn sub() {
+        assert_eq!(
+            Number::from_str("1.5k").unwrap() - Number::from_str("300").unwrap(),
+            Number::from_str("1200").unwrap()
+        )
+    }
+
+    #[test]
+    fn mul() {
+        assert_eq!(
+            Number::from_str("1.5k").unwrap() * Number::from_str("2").unwrap(),
+            Number::from_str("3000").unwrap()
+        )
+    }
+
+    #[test]
+    fn div() {
+        assert_eq!(
+            Number::from_str("3000").unwrap() / Number::from_str("2").unwrap(),
+            Number::from_str("1500").unwrap()
+        )
+    }
+
+    #[test]
+    #[should_panic]
+    fn div_by_zero_panics() {
+        let _ = Number::from_str("5").unwrap() / Number::from_str("0").unwrap();
+    }
+
+    #[test]
+    fn rem() {
+        assert_eq!(
+            Number::from_str("1.5k").unwrap() % Number::from_str("400").unwrap(),
+            Number::from_str("300").unwrap()
+        )
+    }
+
+    #[test]
+    #[should_panic]
+    fn rem_by_zero_panics() {
+        let _ = Number::from_str("5").unwrap() % Number::from_str("0").unwrap();
+    }
+
+    #[test]
+    fn neg() {
+        assert_eq!(
+            -Number::from_str("1.5k").unwrap(),
+            Number::from_str("-1500").unwrap()
+        )
+    }
+
+    #[test]
+    fn add_assign() {
+        let mut n = Number::from_str("1k").unwrap();
+        n += Number::from_str("500").unwrap();
+        assert_eq!(n, Number::from_str("1500").unwrap())
+    }
+
+    #[test]
+    fn to_si_string_kilo() {
+        assert_eq!(Number::from_str("4730").unwrap().to_si_string(3), "4.73k")
+    }
+
+    #[test]
+    fn to_si_string_from_exponent() {
+        assert_eq!(Number::from_str("4.73e3").unwrap().to_si_string(3), "4.73k")
+    }
+
+    #[test]
+    fn to_si_string_negative() {
+        assert_eq!(Number::from_str("-1500").unwrap().to_si_string(3), "-1.50k")
+    }
+
+    #[test]
+    fn to_si_string_zero() {
+        assert_eq!(Number::default().to_si_string(3), "0")
+    }
+
+    #[test]
+    fn to_si_string_pico() {
+        assert_eq!(Number::from_str("1.23p").unwrap().to_si_string(3), "1.23p")
+    }
+
+    #[test]
+    fn to_si_string_rounds_up_to_next_prefix() {
+        assert_eq!(Number::from_str("999.995").unwrap().to_si_string(3), "1.00k")
+    }
+
+    #[test]
+    fn to_si_string_clamps_at_table_edge() {
+        assert_eq!(Number::from_str("1e15").unwrap().to_si_string(3), "1000T")
+    }
+
+    #[test]
+    fn to_si_string_rounds_up_within_same_tier() {
+        assert_eq!(Number::from_str("99.96").unwrap().to_si_string(3), "100")
+    }
+
+    #[test]
+    fn to_si_string_rounds_up_within_same_tier_fraction() {
+        assert_eq!(Number::from_str("9.995p").unwrap().to_si_string(3), "10.0p")
+    }
+
+    #[test]
+    fn display_alternate_uses_si_string() {
+        assert_eq!(format!("{:#}", Number::from_str("4.73e3").unwrap()), "4.73k")
+    }
+
+    #[test]
+    fn hex_literal() {
+        assert_eq!(
+            Number::from_str("0x10"),
+            Ok( Number::from_parts(16, 0, String::from("0x10")) )
+        )
+    }
+
+    #[test]
+    fn hex_literal_with_unit() {
+        assert_eq!(
+            Number::from_str("0x10k"),
+            Ok( Number::from_parts(16, 3, String::from("0x10k")) )
+        )
+    }
+
+    #[test]
+    fn binary_literal() {
+        assert_eq!(
+            Number::from_str("0b101"),
+            Ok( Number::from_parts(5, 0, String::from("0b101")) )
+        )
+    }
+
+    #[test]
+    fn octal_literal_explicit() {
+        assert_eq!(
+            Number::from_str("0o17"),
+            Ok( Number::from_parts(15, 0, String::from("0o17")) )
+        )
+    }
+
+    #[test]
+    fn octal_literal_bare_leading_zero() {
+        assert_eq!(
+            Number::from_str("017"),
+            Ok( Number::from_parts(15, 0, String::from("017")) )
+        )
+    }
+
+    #[test]
+    fn leading_zero_decimal_not_octal() {
+        assert_eq!(
+            Number::from_str("08"),
+            Ok( Number::from_parts(8, 0, String::from("08")) )
+        )
+    }
+
+    #[test]
+    fn leading_zero_decimal_float_not_octal() {
+        assert_eq!(
+            Number::from_str("09.5"),
+            Ok( Number::from_parts(95, -1, String::from("09.5")) )
+        )
+    }
+
+    #[test]
+    fn leading_zero_octal_looking_float_not_octal() {
+        assert_eq!(
+            Number::from_str("017.5"),
+            Ok( Number::from_parts(175, -1, String::from("017.5")) )
+        )
+    }
+
+    #[test]
+    fn leading_zero_octal_looking_exponent_not_octal() {
+        assert_eq!(
+            Number::from_str("017e2"),
+            Ok( Number::from_parts(17, 2, String::from("017e2")) )
+        )
+    }
+
+    #[test]
+    fn radix_literal_overflow() {
+        assert_eq!(
+            Number::from_str("0xffffffffffffffffffffffffffffffffff").unwrap_err().kind(),
+            NumberErrorKind::Overflow
+        )
+    }
+
+    #[test]
+    fn negative_hex_literal() {
+        assert_eq!(
+            Number::from_str("-0x10"),
+            Ok( Number::from_parts(-16, 0, String::from("-0x10")) )
+        )
+    }
+
+    #[test]
+    fn invalid_radix_with_decimal_point() {
+        assert_eq!(
+            Number::from_str("0x1.5"),
+            Err( ParseNumberError{ kind: NumberErrorKind::Invalid, position: 3 } )
+        )
+    }
+
+    #[test]
+    fn invalid_radix_with_exponent() {
+        assert_eq!(
+            Number::from_str("0b101e5"),
+            Err( ParseNumberError{ kind: NumberErrorKind::Invalid, position: 5 } )
+        )
+    }
+
+    #[test]
+    fn unit_spelled_out_farad() {
+        assert_eq!(Number::from_str("1.23pFarad").unwrap().unit(), Some(Unit::Farad))
+    }
+
+    #[test]
+    fn unit_direct_farad_no_multiplier() {
+        let n = Number::from_str("1.23Farad").unwrap();
+        assert_eq!(n.unit(), Some(Unit::Farad));
+        assert_eq!(n.value_exact(), (123, -2));
+    }
+
+    #[test]
+    fn unit_direct_farad_plural() {
+        assert_eq!(Number::from_str("10Farads").unwrap().unit(), Some(Unit::Farad))
+    }
+
+    #[test]
+    fn bare_f_is_still_femto() {
+        let n = Number::from_str("5F").unwrap();
+        assert_eq!(n.unit(), None);
+        assert_eq!(n.value_exact(), (5, -15));
+    }
+
+    #[test]
+    fn unit_ohm() {
+        assert_eq!(Number::from_str("10Ohm").unwrap().unit(), Some(Unit::Ohm))
+    }
+
+    #[test]
+    fn unit_henry_letter() {
+        let n = Number::from_str("10H").unwrap();
+        assert_eq!(n.unit(), Some(Unit::Henry));
+        assert_eq!(n.value_exact(), (10, 0));
+    }
+
+    #[test]
+    fn unit_volt() {
+        assert_eq!(Number::from_str("10V").unwrap().unit(), Some(Unit::Volt))
+    }
+
+    #[test]
+    fn unit_amp() {
+        assert_eq!(Number::from_str("10A").unwrap().unit(), Some(Unit::Amp))
+    }
+
+    #[test]
+    fn unit_second() {
+        assert_eq!(Number::from_str("10s").unwrap().unit(), Some(Unit::Second))
+    }
+
+    #[test]
+    fn unit_hertz() {
+        assert_eq!(Number::from_str("10Hz").unwrap().unit(), Some(Unit::Hertz))
+    }
+
+    #[test]
+    fn unit_with_multiplier() {
+        let n = Number::from_str("10kOhm").unwrap();
+        assert_eq!(n.unit(), Some(Unit::Ohm));
+        assert_eq!(n.value_exact(), (10, 3));
+    }
+
+    #[test]
+    fn from_str_strict_accepts_recognized_unit() {
+        assert_eq!(
+            Number::from_str_strict("1.23pFarad").unwrap(),
+            Number::from_str("1.23pFarad").unwrap()
+        )
+    }
+
+    #[test]
+    fn from_str_strict_rejects_unrecognized_unit() {
+        assert_eq!(
+            Number::from_str_strict("1.2kFrobs").unwrap_err().kind(),
+            NumberErrorKind::InvalidUnit
+        )
+    }
+
+    #[test]
+    fn from_str_lenient_discards_unrecognized_unit() {
+        assert_eq!(
+            Number::from_str("1.2kFrobs").unwrap().value_exact(),
+            (12, 2)
+        )
+    }
+
+    #[test]
+    fn eq_with_unit_requires_matching_units() {
+        let ohms = Number::from_str("1kOhm").unwrap();
+        let plain = Number::from_str("1k").unwrap();
+        assert_eq!(ohms, plain);
+        assert!(!ohms.eq_with_unit(&plain));
+    }
+
+    #[test]
+    fn partial_cmp_with_unit_none_across_units() {
+        let farads = Number::from_str("1Farad").unwrap();
+        let henries = Number::from_str("1H").unwrap();
+        assert_eq!(farads.partial_cmp_with_unit(&henries), None);
+    }
+
+    #[test]
+    fn partial_cmp_with_unit_some_when_matching() {
+        let a = Number::from_str("1kOhm").unwrap();
+        let b = Number::from_str("2kOhm").unwrap();
+        assert_eq!(a.partial_cmp_with_unit(&b), Some(Ordering::Less));
+    }
 }